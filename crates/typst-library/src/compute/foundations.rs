@@ -1,3 +1,4 @@
+use typst::diag::SourceDiagnostic;
 use typst::eval::{
     Datetime, Duration, EvalMode, Module, Never, NoneValue, Plugin, Regex, Repr, Version,
 };
@@ -26,6 +27,7 @@ pub(super) fn define(global: &mut Scope) {
     global.define_type::<Plugin>();
     global.define_func::<repr>();
     global.define_func::<panic>();
+    global.define_func::<todo>();
     global.define_func::<assert>();
     global.define_func::<eval>();
 }
@@ -83,6 +85,42 @@ pub fn panic(
     Err(msg)
 }
 
+/// Warns about unfinished code.
+///
+/// Unlike [`panic`]($panic), this does not stop compilation, so the rest of
+/// the document can still be looked at. Arguments are displayed to the user as
+/// strings, converting with `repr` if necessary.
+///
+/// # Example
+/// The code below produces the warning
+/// `not yet implemented: "write the conclusion"`.
+/// ```typ
+/// #todo("write the conclusion")
+/// ```
+#[func]
+pub fn todo(
+    /// The virtual machine.
+    vm: &mut Vm,
+    /// The callsite span.
+    span: Span,
+    /// The values to display to the user.
+    #[variadic]
+    values: Vec<Value>,
+) -> NoneValue {
+    let mut msg = EcoString::from("not yet implemented");
+    if !values.is_empty() {
+        msg.push_str(": ");
+        for (i, value) in values.iter().enumerate() {
+            if i > 0 {
+                msg.push_str(", ");
+            }
+            msg.push_str(&value.repr());
+        }
+    }
+    vm.vt.tracer.warn(SourceDiagnostic::warning(span, msg));
+    NoneValue
+}
+
 /// Ensures that a condition is fulfilled.
 ///
 /// Fails with an error if the condition is not fulfilled. Does not
@@ -4,6 +4,7 @@
 
 use std::num::NonZeroUsize;
 use std::ops::Deref;
+use std::str::FromStr;
 
 use ecow::EcoString;
 use unscanny::Scanner;
@@ -87,7 +88,7 @@ impl<'a> Markup<'a> {
 }
 
 /// An expression in markup, math or code.
-#[derive(Debug, Copy, Clone, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum Expr<'a> {
     /// Plain text without markup.
     Text(Text<'a>),
@@ -1024,18 +1025,7 @@ impl Numeric<'_> {
 
         let split = text.len() - count;
         let value = text[..split].parse().unwrap_or_default();
-        let unit = match &text[split..] {
-            "pt" => Unit::Pt,
-            "mm" => Unit::Mm,
-            "cm" => Unit::Cm,
-            "in" => Unit::In,
-            "deg" => Unit::Deg,
-            "rad" => Unit::Rad,
-            "em" => Unit::Em,
-            "fr" => Unit::Fr,
-            "%" => Unit::Percent,
-            _ => Unit::Percent,
-        };
+        let unit = text[split..].parse().unwrap_or(Unit::Percent);
 
         (value, unit)
     }
@@ -1064,6 +1054,42 @@ pub enum Unit {
     Percent,
 }
 
+impl Unit {
+    /// The string representation of this unit, as written after a number.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Pt => "pt",
+            Self::Mm => "mm",
+            Self::Cm => "cm",
+            Self::In => "in",
+            Self::Rad => "rad",
+            Self::Deg => "deg",
+            Self::Em => "em",
+            Self::Fr => "fr",
+            Self::Percent => "%",
+        }
+    }
+}
+
+impl FromStr for Unit {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "pt" => Self::Pt,
+            "mm" => Self::Mm,
+            "cm" => Self::Cm,
+            "in" => Self::In,
+            "rad" => Self::Rad,
+            "deg" => Self::Deg,
+            "em" => Self::Em,
+            "fr" => Self::Fr,
+            "%" => Self::Percent,
+            _ => return Err("unknown unit"),
+        })
+    }
+}
+
 node! {
     /// A quoted string: `"..."`.
     Str
@@ -1175,7 +1201,7 @@ impl<'a> Array<'a> {
 }
 
 /// An item in an array.
-#[derive(Debug, Copy, Clone, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum ArrayItem<'a> {
     /// A bare expression: `12`.
     Pos(Expr<'a>),
@@ -1212,7 +1238,7 @@ impl<'a> Dict<'a> {
 }
 
 /// An item in an dictionary expression.
-#[derive(Debug, Copy, Clone, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum DictItem<'a> {
     /// A named pair: `thickness: 3pt`.
     Named(Named<'a>),
@@ -1325,7 +1351,7 @@ impl UnOp {
     /// The precedence of this operator.
     pub fn precedence(self) -> usize {
         match self {
-            Self::Pos | Self::Neg => 7,
+            Self::Pos | Self::Neg => 8,
             Self::Not => 4,
         }
     }
@@ -1384,10 +1410,18 @@ pub enum BinOp {
     Mul,
     /// The division operator: `/`.
     Div,
+    /// The remainder operator: `%`.
+    Mod,
+    /// The exponentiation operator: `^`.
+    Pow,
     /// The short-circuiting boolean `and`.
     And,
     /// The short-circuiting boolean `or`.
     Or,
+    /// The exclusive range operator: `..`.
+    Range,
+    /// The inclusive range operator: `..=`.
+    RangeInclusive,
     /// The equality operator: `==`.
     Eq,
     /// The inequality operator: `!=`.
@@ -1424,8 +1458,12 @@ impl BinOp {
             SyntaxKind::Minus => Self::Sub,
             SyntaxKind::Star => Self::Mul,
             SyntaxKind::Slash => Self::Div,
+            SyntaxKind::Percent => Self::Mod,
+            SyntaxKind::Hat => Self::Pow,
             SyntaxKind::And => Self::And,
             SyntaxKind::Or => Self::Or,
+            SyntaxKind::Dots => Self::Range,
+            SyntaxKind::DotsEq => Self::RangeInclusive,
             SyntaxKind::EqEq => Self::Eq,
             SyntaxKind::ExclEq => Self::Neq,
             SyntaxKind::Lt => Self::Lt,
@@ -1445,10 +1483,14 @@ impl BinOp {
     /// The precedence of this operator.
     pub fn precedence(self) -> usize {
         match self {
-            Self::Mul => 6,
-            Self::Div => 6,
-            Self::Add => 5,
-            Self::Sub => 5,
+            Self::Pow => 9,
+            Self::Mul => 7,
+            Self::Div => 7,
+            Self::Mod => 7,
+            Self::Add => 6,
+            Self::Sub => 6,
+            Self::Range => 5,
+            Self::RangeInclusive => 5,
             Self::Eq => 4,
             Self::Neq => 4,
             Self::Lt => 4,
@@ -1474,8 +1516,12 @@ impl BinOp {
             Self::Sub => Assoc::Left,
             Self::Mul => Assoc::Left,
             Self::Div => Assoc::Left,
+            Self::Mod => Assoc::Left,
+            Self::Pow => Assoc::Right,
             Self::And => Assoc::Left,
             Self::Or => Assoc::Left,
+            Self::Range => Assoc::Left,
+            Self::RangeInclusive => Assoc::Left,
             Self::Eq => Assoc::Left,
             Self::Neq => Assoc::Left,
             Self::Lt => Assoc::Left,
@@ -1499,8 +1545,12 @@ impl BinOp {
             Self::Sub => "-",
             Self::Mul => "*",
             Self::Div => "/",
+            Self::Mod => "%",
+            Self::Pow => "^",
             Self::And => "and",
             Self::Or => "or",
+            Self::Range => "..",
+            Self::RangeInclusive => "..=",
             Self::Eq => "==",
             Self::Neq => "!=",
             Self::Lt => "<",
@@ -1523,7 +1573,8 @@ impl BinOp {
 pub enum Assoc {
     /// Left-associative: `a + b + c` is equivalent to `(a + b) + c`.
     Left,
-    /// Right-associative: `a = b = c` is equivalent to `a = (b = c)`.
+    /// Right-associative: `a = b = c` is equivalent to `a = (b = c)` and
+    /// `a ^ b ^ c` is equivalent to `a ^ (b ^ c)`.
     Right,
 }
 
@@ -1574,7 +1625,7 @@ impl<'a> Args<'a> {
 }
 
 /// An argument to a function call.
-#[derive(Debug, Copy, Clone, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum Arg<'a> {
     /// A positional argument: `12`.
     Pos(Expr<'a>),
@@ -1661,7 +1712,7 @@ node! {
 }
 
 /// A parameter to a closure.
-#[derive(Debug, Copy, Clone, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum Param<'a> {
     /// A positional parameter: `x`.
     Pos(Pattern<'a>),
@@ -1712,7 +1763,7 @@ impl<'a> Destructuring<'a> {
 }
 
 /// The kind of an element in a destructuring pattern.
-#[derive(Debug, Copy, Clone, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum DestructuringKind<'a> {
     /// An expression: `x`.
     Normal(Expr<'a>),
@@ -1745,7 +1796,7 @@ impl<'a> AstNode<'a> for DestructuringKind<'a> {
 }
 
 /// The kind of a pattern.
-#[derive(Debug, Copy, Clone, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum Pattern<'a> {
     /// A single expression: `x`.
     Normal(Expr<'a>),
@@ -2002,7 +2053,7 @@ impl<'a> ModuleImport<'a> {
 }
 
 /// The items that ought to be imported from a file.
-#[derive(Debug, Copy, Clone, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum Imports<'a> {
     /// All items in the scope of the file should be imported.
     Wildcard,
@@ -2027,7 +2078,7 @@ impl<'a> ImportItems<'a> {
 }
 
 /// An imported item, potentially renamed to another identifier.
-#[derive(Debug, Copy, Clone, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum ImportItem<'a> {
     /// A non-renamed import (the item's name in the scope is the same as its
     /// name).
@@ -2111,3 +2162,28 @@ impl<'a> FuncReturn<'a> {
         self.0.cast_last_match()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unit_round_trip() {
+        for unit in [
+            Unit::Pt,
+            Unit::Mm,
+            Unit::Cm,
+            Unit::In,
+            Unit::Rad,
+            Unit::Deg,
+            Unit::Em,
+            Unit::Fr,
+            Unit::Percent,
+        ] {
+            assert_eq!(unit.as_str().parse(), Ok(unit));
+        }
+
+        assert_eq!("px".parse::<Unit>(), Err("unknown unit"));
+        assert_eq!("".parse::<Unit>(), Err("unknown unit"));
+    }
+}
@@ -201,7 +201,11 @@ pub fn highlight(node: &LinkedNode) -> Option<Tag> {
             Some(SyntaxKind::MathFrac) => Tag::MathOperator,
             _ => Tag::Operator,
         }),
-        SyntaxKind::Hat => Some(Tag::MathOperator),
+        SyntaxKind::Percent => Some(Tag::Operator),
+        SyntaxKind::Hat => Some(match node.parent_kind() {
+            Some(SyntaxKind::Binary) => Tag::Operator,
+            _ => Tag::MathOperator,
+        }),
         SyntaxKind::Prime => Some(Tag::MathOperator),
         SyntaxKind::Dot => Some(Tag::Punctuation),
         SyntaxKind::Eq => match node.parent_kind() {
@@ -219,6 +223,7 @@ pub fn highlight(node: &LinkedNode) -> Option<Tag> {
         SyntaxKind::StarEq => Some(Tag::Operator),
         SyntaxKind::SlashEq => Some(Tag::Operator),
         SyntaxKind::Dots => Some(Tag::Operator),
+        SyntaxKind::DotsEq => Some(Tag::Operator),
         SyntaxKind::Arrow => Some(Tag::Operator),
         SyntaxKind::Root => Some(Tag::MathOperator),
 
@@ -108,7 +108,9 @@ pub enum SyntaxKind {
     Minus,
     /// The division operator and fraction operator in math: `/`.
     Slash,
-    /// The superscript operator in math: `^`.
+    /// The remainder operator: `%`.
+    Percent,
+    /// The exponentiation operator and superscript operator in math: `^`.
     Hat,
     /// The prime in math: `'`.
     Prime,
@@ -136,8 +138,10 @@ pub enum SyntaxKind {
     StarEq,
     /// The divide-assign operator: `/=`.
     SlashEq,
-    /// The spread operator: `..`.
+    /// The spread and exclusive range operator: `..`.
     Dots,
+    /// The inclusive range operator: `..=`.
+    DotsEq,
     /// An arrow between a closure's parameters and body: `=>`.
     Arrow,
     /// A root: `√`, `∛` or `∜`.
@@ -401,6 +405,7 @@ impl SyntaxKind {
             Self::Plus => "plus",
             Self::Minus => "minus",
             Self::Slash => "slash",
+            Self::Percent => "percent sign",
             Self::Hat => "hat",
             Self::Prime => "prime",
             Self::Dot => "dot",
@@ -416,6 +421,7 @@ impl SyntaxKind {
             Self::StarEq => "multiply-assign operator",
             Self::SlashEq => "divide-assign operator",
             Self::Dots => "dots",
+            Self::DotsEq => "inclusive range operator",
             Self::Arrow => "arrow",
             Self::Root => "root",
             Self::Not => "operator `not`",
@@ -3,7 +3,7 @@ use unicode_ident::{is_xid_continue, is_xid_start};
 use unicode_segmentation::UnicodeSegmentation;
 use unscanny::Scanner;
 
-use super::SyntaxKind;
+use super::{ast, SyntaxKind};
 
 /// Splits up a string of source code into tokens.
 #[derive(Clone)]
@@ -202,17 +202,8 @@ impl Lexer<'_> {
 
     fn backslash(&mut self) -> SyntaxKind {
         if self.s.eat_if("u{") {
-            let hex = self.s.eat_while(char::is_ascii_alphanumeric);
-            if !self.s.eat_if('}') {
-                return self.error("unclosed Unicode escape sequence");
-            }
-
-            if u32::from_str_radix(hex, 16)
-                .ok()
-                .and_then(std::char::from_u32)
-                .is_none()
-            {
-                return self.error(eco_format!("invalid Unicode codepoint: {}", hex));
+            if let Err(message) = unicode_escape(&mut self.s) {
+                return self.error(message);
             }
 
             return SyntaxKind::Escape;
@@ -457,6 +448,7 @@ impl Lexer<'_> {
             '-' | '\u{2212}' if self.s.eat_if('=') => SyntaxKind::HyphEq,
             '*' if self.s.eat_if('=') => SyntaxKind::StarEq,
             '/' if self.s.eat_if('=') => SyntaxKind::SlashEq,
+            '.' if self.s.eat_if(".=") => SyntaxKind::DotsEq,
             '.' if self.s.eat_if('.') => SyntaxKind::Dots,
             '=' if self.s.eat_if('>') => SyntaxKind::Arrow,
 
@@ -475,6 +467,8 @@ impl Lexer<'_> {
             '-' | '\u{2212}' => SyntaxKind::Minus,
             '*' => SyntaxKind::Star,
             '/' => SyntaxKind::Slash,
+            '%' => SyntaxKind::Percent,
+            '^' => SyntaxKind::Hat,
             '=' => SyntaxKind::Eq,
             '<' => SyntaxKind::Lt,
             '>' => SyntaxKind::Gt,
@@ -543,9 +537,17 @@ impl Lexer<'_> {
             self.s.eat_while(char::is_ascii_digit);
         }
 
-        // Read the suffix.
+        // Read the suffix. A percent sign directly followed by an operand is
+        // the remainder operator instead, as in `10%3`.
         let suffix_start = self.s.cursor();
-        if !self.s.eat_if('%') {
+        if self.s.at('%')
+            && !self
+                .s
+                .scout(1)
+                .map_or(false, |c| c.is_ascii_digit() || c == '(' || is_id_start(c))
+        {
+            self.s.eat();
+        } else {
             self.s.eat_while(char::is_ascii_alphanumeric);
         }
 
@@ -569,10 +571,7 @@ impl Lexer<'_> {
             return kind;
         }
 
-        if !matches!(
-            suffix,
-            "pt" | "mm" | "cm" | "in" | "deg" | "rad" | "em" | "fr" | "%"
-        ) {
+        if suffix.parse::<ast::Unit>().is_err() {
             return self.error(eco_format!("invalid number suffix: {}", suffix));
         }
 
@@ -580,6 +579,7 @@ impl Lexer<'_> {
     }
 
     fn string(&mut self) -> SyntaxKind {
+        let start = self.s.cursor();
         let mut escaped = false;
         self.s.eat_until(|c| {
             let stop = c == '"' && !escaped;
@@ -587,14 +587,49 @@ impl Lexer<'_> {
             stop
         });
 
+        let body = self.s.from(start);
         if !self.s.eat_if('"') {
             return self.error("unclosed string");
         }
 
+        let mut s = Scanner::new(body);
+        while let Some(c) = s.eat() {
+            if c != '\\' {
+                continue;
+            }
+
+            if !s.eat_if("u{") {
+                s.eat();
+                continue;
+            }
+
+            if let Err(message) = unicode_escape(&mut s) {
+                return self.error(message);
+            }
+        }
+
         SyntaxKind::Str
     }
 }
 
+/// Check the rest of a Unicode escape sequence after its opening `\u{`.
+fn unicode_escape(s: &mut Scanner) -> Result<(), EcoString> {
+    let hex = s.eat_while(char::is_ascii_alphanumeric);
+    if !s.eat_if('}') {
+        return Err("unclosed Unicode escape sequence".into());
+    }
+
+    if u32::from_str_radix(hex, 16)
+        .ok()
+        .and_then(std::char::from_u32)
+        .is_none()
+    {
+        return Err(eco_format!("invalid Unicode codepoint: {}", hex));
+    }
+
+    Ok(())
+}
+
 /// Try to parse an identifier into a keyword.
 fn keyword(ident: &str) -> Option<SyntaxKind> {
     Some(match ident {
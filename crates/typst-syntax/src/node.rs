@@ -987,4 +987,24 @@ mod tests {
         assert_eq!(leaf.text(), " ");
         assert_eq!(next.text(), "10");
     }
+
+    #[test]
+    fn test_multiple_errors() {
+        let source = Source::detached("#let\n#let v =\n#{1 2}\n");
+        let messages: Vec<String> = source
+            .root()
+            .errors()
+            .into_iter()
+            .map(|error| error.message.to_string())
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "expected identifier",
+                "expected expression",
+                "expected semicolon or line break",
+            ]
+        );
+        assert_eq!(source.root().clone().into_text().as_str(), source.text());
+    }
 }
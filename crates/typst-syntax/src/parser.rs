@@ -39,6 +39,10 @@ fn markup(
     min_indent: usize,
     mut stop: impl FnMut(&Parser) -> bool,
 ) {
+    if !p.enter_nested() {
+        return;
+    }
+
     let m = p.marker();
     let mut nesting: usize = 0;
     while !p.eof() {
@@ -65,11 +69,13 @@ fn markup(
         }
     }
     p.wrap(m, SyntaxKind::Markup);
+    p.exit_nested();
 }
 
 pub(super) fn reparse_markup(
     text: &str,
     range: Range<usize>,
+    depth: usize,
     at_start: &mut bool,
     nesting: &mut usize,
     mut stop: impl FnMut(SyntaxKind) -> bool,
@@ -95,7 +101,9 @@ pub(super) fn reparse_markup(
             p.unexpected();
         }
     }
-    (p.balanced && p.current_start() == range.end).then(|| p.finish())
+    (p.balanced && p.current_start() == range.end)
+        .then(|| p.finish())
+        .filter(|nodes| nodes.iter().all(|node| within_depth(depth, node)))
 }
 
 fn markup_expr(p: &mut Parser, at_start: &mut bool) {
@@ -251,8 +259,13 @@ fn math_expr(p: &mut Parser) {
 }
 
 fn math_expr_prec(p: &mut Parser, min_prec: usize, stop: SyntaxKind) {
+    if !p.enter_nested() {
+        return;
+    }
+
     let m = p.marker();
     let mut continuable = false;
+    let mut chain = 0;
     match p.current() {
         SyntaxKind::Hash => embedded_code_expr(p),
         SyntaxKind::MathIdent => {
@@ -265,6 +278,10 @@ fn math_expr_prec(p: &mut Parser, min_prec: usize, stop: SyntaxKind) {
                     SyntaxKind::MathIdent | SyntaxKind::Text
                 )
             {
+                if !p.check_depth(chain) {
+                    break;
+                }
+                chain += 1;
                 p.convert(SyntaxKind::Dot);
                 p.convert(SyntaxKind::Ident);
                 p.wrap(m, SyntaxKind::FieldAccess);
@@ -331,6 +348,10 @@ fn math_expr_prec(p: &mut Parser, min_prec: usize, stop: SyntaxKind) {
 
     while !p.eof() && !p.at(stop) {
         if p.directly_at(SyntaxKind::Text) && p.current_text() == "!" {
+            if !p.check_depth(chain) {
+                break;
+            }
+            chain += 1;
             p.eat();
             p.wrap(m, SyntaxKind::Math);
             continue;
@@ -378,6 +399,11 @@ fn math_expr_prec(p: &mut Parser, min_prec: usize, stop: SyntaxKind) {
             ast::Assoc::Right => {}
         }
 
+        if !p.check_depth(chain) {
+            break;
+        }
+        chain += 1;
+
         if kind == SyntaxKind::MathFrac {
             math_unparen(p, m);
         }
@@ -395,6 +421,8 @@ fn math_expr_prec(p: &mut Parser, min_prec: usize, stop: SyntaxKind) {
 
         p.wrap(m, kind);
     }
+
+    p.exit_nested();
 }
 
 fn maybe_delimited(p: &mut Parser) -> bool {
@@ -633,6 +661,10 @@ fn code_expr_prec(
     min_prec: usize,
     allow_destructuring: bool,
 ) {
+    if !p.enter_nested() {
+        return;
+    }
+
     let m = p.marker();
     if let (false, Some(op)) = (atomic, ast::UnOp::from_kind(p.current())) {
         p.eat();
@@ -642,9 +674,16 @@ fn code_expr_prec(
         code_primary(p, atomic, allow_destructuring);
     }
 
+    // Left-associative chains grow the tree without recursing, so their
+    // length counts toward the nesting depth, too.
+    let mut chain = 0;
     loop {
         if p.directly_at(SyntaxKind::LeftParen) || p.directly_at(SyntaxKind::LeftBracket)
         {
+            if !p.check_depth(chain) {
+                break;
+            }
+            chain += 1;
             args(p);
             p.wrap(m, SyntaxKind::FuncCall);
             continue;
@@ -657,7 +696,12 @@ fn code_expr_prec(
             break;
         }
 
-        if p.eat_if(SyntaxKind::Dot) {
+        if p.at(SyntaxKind::Dot) {
+            if !p.check_depth(chain) {
+                break;
+            }
+            chain += 1;
+            p.eat();
             p.expect(SyntaxKind::Ident);
             p.wrap(m, SyntaxKind::FieldAccess);
             continue;
@@ -686,6 +730,10 @@ fn code_expr_prec(
                 ast::Assoc::Right => {}
             }
 
+            if !p.check_depth(chain) {
+                break;
+            }
+            chain += 1;
             p.eat();
             code_expr_prec(p, false, prec, false);
             p.wrap(m, SyntaxKind::Binary);
@@ -694,6 +742,8 @@ fn code_expr_prec(
 
         break;
     }
+
+    p.exit_nested();
 }
 
 fn code_primary(p: &mut Parser, atomic: bool, allow_destructuring: bool) {
@@ -758,12 +808,17 @@ fn block(p: &mut Parser) {
     }
 }
 
-pub(super) fn reparse_block(text: &str, range: Range<usize>) -> Option<SyntaxNode> {
+pub(super) fn reparse_block(
+    text: &str,
+    range: Range<usize>,
+    depth: usize,
+) -> Option<SyntaxNode> {
     let mut p = Parser::new(text, range.start, LexMode::Code);
     assert!(p.at(SyntaxKind::LeftBracket) || p.at(SyntaxKind::LeftBrace));
     block(&mut p);
     (p.balanced && p.prev_end() == range.end)
         .then(|| p.finish().into_iter().next().unwrap())
+        .filter(|node| within_depth(depth, node))
 }
 
 fn code_block(p: &mut Parser) {
@@ -1432,6 +1487,30 @@ fn validate_pattern<'a>(
     }
 }
 
+/// How deeply markup, math, and code constructs may be nested, counting both
+/// recursive constructs and the length of left-associative chains like
+/// `a + b + c` or `f()()`. Deeper nesting is reported as an error to prevent
+/// stack overflows, both here and in later stages that recurse over the syntax
+/// tree.
+const MAX_DEPTH: usize = 256;
+
+/// Whether a node reparsed in isolation below `depth` ancestors is guaranteed
+/// to look the same as in a full parse.
+///
+/// The reparse starts with a fresh nesting depth, so it is blind to the limit.
+/// Each level counted toward the limit adds at least one level to the syntax
+/// tree, though. Thus, if the ancestors and the node's own height stay below
+/// the limit, a full parse cannot have hit it either.
+fn within_depth(depth: usize, node: &SyntaxNode) -> bool {
+    // The limit also bounds the height of the tree, so this recursion can't
+    // overflow the stack.
+    fn height(node: &SyntaxNode) -> usize {
+        1 + node.children().map(height).max().unwrap_or(0)
+    }
+
+    depth + height(node) < MAX_DEPTH
+}
+
 /// Manages parsing of a stream of tokens.
 struct Parser<'s> {
     text: &'s str,
@@ -1443,6 +1522,7 @@ struct Parser<'s> {
     nodes: Vec<SyntaxNode>,
     newline_modes: Vec<NewlineMode>,
     balanced: bool,
+    depth: usize,
 }
 
 /// How to proceed with parsing when seeing a newline.
@@ -1473,6 +1553,7 @@ impl<'s> Parser<'s> {
             nodes: vec![],
             newline_modes: vec![],
             balanced: true,
+            depth: 0,
         }
     }
 
@@ -1737,6 +1818,33 @@ impl<'s> Parser<'s> {
         self.convert_to_error(eco_format!("unexpected {}", self.current.name()));
     }
 
+    /// Enter a nested markup, math, or code construct. If the maximum nesting
+    /// depth is exceeded, the next token is turned into an error instead and
+    /// `false` is returned.
+    fn enter_nested(&mut self) -> bool {
+        if !self.check_depth(0) {
+            return false;
+        }
+        self.depth += 1;
+        true
+    }
+
+    /// Check that `extra` more levels fit below the current nesting depth. If
+    /// not, the next token is turned into an error instead and `false` is
+    /// returned.
+    fn check_depth(&mut self, extra: usize) -> bool {
+        if self.depth + extra >= MAX_DEPTH && !self.eof() {
+            self.convert_to_error("maximum nesting depth exceeded".into());
+            return false;
+        }
+        true
+    }
+
+    /// Leave a nested construct entered with `enter_nested`.
+    fn exit_nested(&mut self) {
+        self.depth -= 1;
+    }
+
     /// Consume the next token and turn it into an error.
     fn convert_to_error(&mut self, message: EcoString) {
         let kind = self.current;
@@ -1786,3 +1894,50 @@ impl<'s> Parser<'s> {
         self.nodes.drain(start..end);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_depth() {
+        fn exceeded(root: &SyntaxNode) -> bool {
+            root.errors()
+                .iter()
+                .any(|error| error.message.as_str() == "maximum nesting depth exceeded")
+        }
+
+        #[track_caller]
+        fn test(text: &str, expected: bool) {
+            assert_eq!(exceeded(&parse(text)), expected);
+        }
+
+        #[track_caller]
+        fn test_math(text: &str, expected: bool) {
+            assert_eq!(exceeded(&parse_math(text)), expected);
+        }
+
+        let parens = |depth| format!("#{}1{}", "(".repeat(depth), ")".repeat(depth));
+        test(&parens(MAX_DEPTH / 2), false);
+        test(&parens(MAX_DEPTH - 8), false);
+        test(&parens(MAX_DEPTH), true);
+        test(&parens(10 * MAX_DEPTH), true);
+        test(&format!("{}a{}", "*_".repeat(MAX_DEPTH), "_*".repeat(MAX_DEPTH)), true);
+
+        // Flat constructs must not add up.
+        test(&format!("{}#(1)", "$a^b$ ".repeat(4 * MAX_DEPTH)), false);
+        test_math(&"a + ".repeat(4 * MAX_DEPTH), false);
+
+        // Nested math.
+        test(&format!("${}a{}$", "(".repeat(MAX_DEPTH), ")".repeat(MAX_DEPTH)), true);
+        test_math(&format!("{}a", "a^".repeat(MAX_DEPTH)), true);
+
+        // Left-associative chains count toward the limit.
+        test(&format!("#(1{})", " + 1".repeat(MAX_DEPTH / 2)), false);
+        test(&format!("#(1{})", " + 1".repeat(100 * MAX_DEPTH)), true);
+        test(&format!("#f{}", "()".repeat(MAX_DEPTH / 2)), false);
+        test(&format!("#f{}", "()".repeat(100 * MAX_DEPTH)), true);
+        test(&format!("#x{}", ".y".repeat(100 * MAX_DEPTH)), true);
+        test_math(&format!("a{}", "/a".repeat(100 * MAX_DEPTH)), true);
+    }
+}
@@ -18,7 +18,7 @@ pub fn reparse(
     replaced: Range<usize>,
     replacement_len: usize,
 ) -> Range<usize> {
-    try_reparse(text, replaced, replacement_len, None, root, 0).unwrap_or_else(|| {
+    try_reparse(text, replaced, replacement_len, None, root, 0, 0).unwrap_or_else(|| {
         let id = root.span().id();
         *root = parse(text);
         if let Some(id) = id {
@@ -28,7 +28,7 @@ pub fn reparse(
     })
 }
 
-/// Try to reparse inside the given node.
+/// Try to reparse inside the given node, which has `depth` ancestors.
 fn try_reparse(
     text: &str,
     replaced: Range<usize>,
@@ -36,6 +36,7 @@ fn try_reparse(
     parent_kind: Option<SyntaxKind>,
     node: &mut SyntaxNode,
     offset: usize,
+    depth: usize,
 ) -> Option<Range<usize>> {
     // The range of children which overlap with the edit.
     #[allow(clippy::reversed_empty_ranges)]
@@ -62,6 +63,7 @@ fn try_reparse(
                 Some(node_kind),
                 child,
                 cursor,
+                depth + 1,
             ) {
                 assert_eq!(child.len(), new_len);
                 let new_desc = child.descendants();
@@ -71,7 +73,7 @@ fn try_reparse(
 
             // If the child is a block, try to reparse the block.
             if child.kind().is_block() {
-                if let Some(newborn) = reparse_block(text, new_range.clone()) {
+                if let Some(newborn) = reparse_block(text, new_range.clone(), depth + 1) {
                     return node
                         .replace_children(i..i + 1, vec![newborn])
                         .is_ok()
@@ -167,6 +169,7 @@ fn try_reparse(
         let reparsed = reparse_markup(
             text,
             new_range.clone(),
+            depth + 1,
             &mut at_start,
             &mut nesting,
             |kind| kind == stop_kind,
@@ -321,4 +324,12 @@ mod tests {
         test("a #while x {\n g(x) \n}  b", 12..12, "//", true);
         test("a#[]b", 3..3, "[hey]", true);
     }
+
+    #[test]
+    fn test_reparse_near_max_depth() {
+        // The edit pushes the innermost block past the parser's nesting limit,
+        // which a reparse in isolation would not notice.
+        let prev = format!("{}a{}", "#[".repeat(126), "]".repeat(126));
+        test(&prev, 252..253, "#(((1)))", false);
+    }
 }
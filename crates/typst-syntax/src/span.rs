@@ -21,9 +21,13 @@ use super::FileId;
 /// - The id of a node is always greater than any id in the subtrees of any left
 ///   sibling and smaller than any id in the subtrees of any right sibling.
 ///
+/// Consequently, spans are ordered by source file first and then by their
+/// position in the syntax tree, with a parent coming before its children. The
+/// detached span comes before all others.
+///
 /// This type takes up 8 bytes and is null-optimized (i.e. `Option<Span>` also
 /// takes 8 bytes).
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Span(NonZeroU64);
 
 impl Span {
@@ -109,6 +113,14 @@ impl<T> Spanned<T> {
     {
         Spanned { v: f(self.v), span: self.span }
     }
+
+    /// Map the value using a fallible function.
+    pub fn and_then<F, U, E>(self, f: F) -> Result<Spanned<U>, E>
+    where
+        F: FnOnce(T) -> Result<U, E>,
+    {
+        Ok(Spanned { v: f(self.v)?, span: self.span })
+    }
 }
 
 impl<T: Debug> Debug for Spanned<T> {
@@ -119,7 +131,8 @@ impl<T: Debug> Debug for Spanned<T> {
 
 #[cfg(test)]
 mod tests {
-    use super::{FileId, Span};
+    use super::{FileId, Span, Spanned};
+    use crate::{Source, SyntaxNode};
 
     #[test]
     fn test_span_encoding() {
@@ -128,4 +141,30 @@ mod tests {
         assert_eq!(span.id(), Some(id));
         assert_eq!(span.number(), 10);
     }
+
+    #[test]
+    fn test_span_order() {
+        fn collect(node: &SyntaxNode, spans: &mut Vec<Span>) {
+            spans.push(node.span());
+            for child in node.children() {
+                collect(child, spans);
+            }
+        }
+
+        let source = Source::detached("#let x = (1, (2, 3)) + y\n= Heading\nText");
+        let mut spans = vec![];
+        collect(source.root(), &mut spans);
+        assert!(spans.windows(2).all(|w| w[0] < w[1]));
+        assert!(Span::detached() < spans[0]);
+    }
+
+    #[test]
+    fn test_spanned_combinators() {
+        let span = Span::new(FileId::from_raw(1), 5).unwrap();
+        let spanned = Spanned::new("12", span);
+        assert_eq!(spanned.map(str::len), Spanned::new(2, span));
+        assert_eq!(spanned.and_then(str::parse::<i64>), Ok(Spanned::new(12, span)));
+        assert!(Spanned::new("x", span).and_then(str::parse::<i64>).is_err());
+        assert_eq!(spanned.as_ref().v, &"12");
+    }
 }
@@ -538,6 +538,27 @@ impl Array {
         Ok(acc)
     }
 
+    /// Reduces all items into a single value by repeatedly applying a function
+    /// to the accumulated value and the next item. The first item serves as
+    /// the initial value. Returns `{none}` if the array is empty.
+    #[func]
+    pub fn reduce(
+        self,
+        /// The virtual machine.
+        vm: &mut Vm,
+        /// The reducing function. Must have two parameters: One for the
+        /// accumulated value and one for an item.
+        reducer: Func,
+    ) -> SourceResult<Value> {
+        let mut iter = self.into_iter();
+        let mut acc = iter.next().unwrap_or_default();
+        for item in iter {
+            let args = Args::new(reducer.span(), [acc, item]);
+            acc = reducer.call_vm(vm, args)?;
+        }
+        Ok(acc)
+    }
+
     /// Sums all items (works for all types that can be added).
     #[func]
     pub fn sum(
@@ -11,7 +11,7 @@ use smallvec::SmallVec;
 use unicode_math_class::MathClass;
 
 use super::{Repr, Type, Value};
-use crate::diag::{At, SourceResult, StrResult};
+use crate::diag::{At, HintedStrResult, SourceResult, StrResult};
 use crate::syntax::{Span, Spanned};
 use crate::util::separated_list;
 
@@ -112,6 +112,20 @@ impl<T: Reflect> Reflect for StrResult<T> {
     }
 }
 
+impl<T: Reflect> Reflect for HintedStrResult<T> {
+    fn input() -> CastInfo {
+        T::input()
+    }
+
+    fn output() -> CastInfo {
+        T::output()
+    }
+
+    fn castable(value: &Value) -> bool {
+        T::castable(value)
+    }
+}
+
 impl<T: Reflect> Reflect for SourceResult<T> {
     fn input() -> CastInfo {
         T::input()
@@ -188,8 +202,8 @@ impl<T: IntoValue + Hash + 'static> IntoValue for Prehashed<T> {
 
 /// Cast a Rust type or result into a [`SourceResult<Value>`].
 ///
-/// Converts `T`, [`StrResult<T>`], or [`SourceResult<T>`] into
-/// [`SourceResult<Value>`] by `Ok`-wrapping or adding span information.
+/// Converts `T`, [`StrResult<T>`], [`HintedStrResult<T>`], or [`SourceResult<T>`]
+/// into [`SourceResult<Value>`] by `Ok`-wrapping or adding span information.
 pub trait IntoResult {
     /// Cast this type into a value.
     fn into_result(self, span: Span) -> SourceResult<Value>;
@@ -207,6 +221,12 @@ impl<T: IntoValue> IntoResult for StrResult<T> {
     }
 }
 
+impl<T: IntoValue> IntoResult for HintedStrResult<T> {
+    fn into_result(self, span: Span) -> SourceResult<Value> {
+        self.map(IntoValue::into_value).at(span)
+    }
+}
+
 impl<T: IntoValue> IntoResult for SourceResult<T> {
     fn into_result(self, _: Span) -> SourceResult<Value> {
         self.map(IntoValue::into_value)
@@ -455,31 +455,44 @@ impl PartialOrd for Datetime {
 }
 
 impl Add<Duration> for Datetime {
-    type Output = Self;
+    type Output = StrResult<Self>;
 
     fn add(self, rhs: Duration) -> Self::Output {
         let rhs: time::Duration = rhs.into();
-        match self {
-            Self::Datetime(datetime) => Self::Datetime(datetime + rhs),
-            Self::Date(date) => Self::Date(date + rhs),
+        Ok(match self {
+            Self::Datetime(datetime) => {
+                Self::Datetime(datetime.checked_add(rhs).ok_or_else(out_of_range)?)
+            }
+            Self::Date(date) => {
+                Self::Date(date.checked_add(rhs).ok_or_else(out_of_range)?)
+            }
             Self::Time(time) => Self::Time(time + rhs),
-        }
+        })
     }
 }
 
 impl Sub<Duration> for Datetime {
-    type Output = Self;
+    type Output = StrResult<Self>;
 
     fn sub(self, rhs: Duration) -> Self::Output {
         let rhs: time::Duration = rhs.into();
-        match self {
-            Self::Datetime(datetime) => Self::Datetime(datetime - rhs),
-            Self::Date(date) => Self::Date(date - rhs),
+        Ok(match self {
+            Self::Datetime(datetime) => {
+                Self::Datetime(datetime.checked_sub(rhs).ok_or_else(out_of_range)?)
+            }
+            Self::Date(date) => {
+                Self::Date(date.checked_sub(rhs).ok_or_else(out_of_range)?)
+            }
             Self::Time(time) => Self::Time(time - rhs),
-        }
+        })
     }
 }
 
+#[cold]
+fn out_of_range() -> &'static str {
+    "datetime is out of range"
+}
+
 impl Sub for Datetime {
     type Output = StrResult<Duration>;
 
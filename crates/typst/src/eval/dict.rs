@@ -8,7 +8,7 @@ use indexmap::IndexMap;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::{array, func, scope, ty, Array, Repr, Str, Value};
-use crate::diag::StrResult;
+use crate::diag::{HintedStrResult, HintedString, StrResult};
 use crate::syntax::is_ident;
 use crate::util::{pretty_array_like, separated_list, ArcExt};
 
@@ -78,15 +78,16 @@ impl Dict {
     }
 
     /// Borrow the value at the given key.
-    pub fn get(&self, key: &str) -> StrResult<&Value> {
-        self.0.get(key).ok_or_else(|| missing_key(key))
+    pub fn get(&self, key: &str) -> HintedStrResult<&Value> {
+        self.0.get(key).ok_or_else(|| with_keys_hint(self, missing_key(key)))
     }
 
     /// Mutably borrow the value the given `key` maps to.
-    pub fn at_mut(&mut self, key: &str) -> StrResult<&mut Value> {
-        Arc::make_mut(&mut self.0)
-            .get_mut(key)
-            .ok_or_else(|| missing_key_no_default(key))
+    pub fn at_mut(&mut self, key: &str) -> HintedStrResult<&mut Value> {
+        if !self.contains(key) {
+            return Err(with_keys_hint(self, missing_key_no_default(key)));
+        }
+        Ok(Arc::make_mut(&mut self.0).get_mut(key).unwrap())
     }
 
     /// Remove the value if the dictionary contains the given key.
@@ -146,12 +147,12 @@ impl Dict {
         /// A default value to return if the key is not part of the dictionary.
         #[named]
         default: Option<Value>,
-    ) -> StrResult<Value> {
+    ) -> HintedStrResult<Value> {
         self.0
             .get(&key)
             .cloned()
             .or(default)
-            .ok_or_else(|| missing_key_no_default(&key))
+            .ok_or_else(|| with_keys_hint(self, missing_key_no_default(&key)))
     }
 
     /// Inserts a new pair into the dictionary and return the value. If the
@@ -326,6 +327,25 @@ fn missing_key(key: &str) -> EcoString {
     eco_format!("dictionary does not contain key {}", key.repr())
 }
 
+/// Adds a hint that lists the first few available keys to a missing key access
+/// error message.
+#[cold]
+fn with_keys_hint(dict: &Dict, message: EcoString) -> HintedString {
+    /// How many keys to list at most.
+    const MAX_KEYS: usize = 10;
+
+    let mut error = HintedString::from(message);
+    if !dict.is_empty() {
+        let mut keys: Vec<_> = dict.0.keys().take(MAX_KEYS).map(Str::repr).collect();
+        if dict.len() > MAX_KEYS {
+            keys.push("…".into());
+        }
+        let hint = eco_format!("available keys are {}", keys.join(", "));
+        error.hints.push(hint);
+    }
+    error
+}
+
 /// The missing key access error message when no default was fiven.
 #[cold]
 fn missing_key_no_default(key: &str) -> EcoString {
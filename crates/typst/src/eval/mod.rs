@@ -1070,8 +1070,14 @@ impl Eval for ast::Binary<'_> {
             ast::BinOp::Sub => apply_binary_expr(self, vm, ops::sub),
             ast::BinOp::Mul => apply_binary_expr(self, vm, ops::mul),
             ast::BinOp::Div => apply_binary_expr(self, vm, ops::div),
+            ast::BinOp::Mod => apply_binary_expr(self, vm, ops::rem),
+            ast::BinOp::Pow => apply_binary_expr(self, vm, ops::pow),
             ast::BinOp::And => apply_binary_expr(self, vm, ops::and),
             ast::BinOp::Or => apply_binary_expr(self, vm, ops::or),
+            ast::BinOp::Range => apply_binary_expr(self, vm, ops::range),
+            ast::BinOp::RangeInclusive => {
+                apply_binary_expr(self, vm, ops::range_inclusive)
+            }
             ast::BinOp::Eq => apply_binary_expr(self, vm, ops::eq),
             ast::BinOp::Neq => apply_binary_expr(self, vm, ops::neq),
             ast::BinOp::Lt => apply_binary_expr(self, vm, ops::lt),
@@ -139,8 +139,8 @@ pub fn add(lhs: Value, rhs: Value) -> StrResult<Value> {
         .into_value(),
 
         (Duration(a), Duration(b)) => Duration(a + b),
-        (Datetime(a), Duration(b)) => Datetime(a + b),
-        (Duration(a), Datetime(b)) => Datetime(b + a),
+        (Datetime(a), Duration(b)) => Datetime((a + b)?),
+        (Duration(a), Datetime(b)) => Datetime((b + a)?),
 
         // Type compatibility.
         (Type(a), Str(b)) => Str(format_str!("{a}{b}")),
@@ -184,7 +184,7 @@ pub fn sub(lhs: Value, rhs: Value) -> StrResult<Value> {
         (Fraction(a), Fraction(b)) => Fraction(a - b),
 
         (Duration(a), Duration(b)) => Duration(a - b),
-        (Datetime(a), Duration(b)) => Datetime(a - b),
+        (Datetime(a), Duration(b)) => Datetime((a - b)?),
         (Datetime(a), Datetime(b)) => Duration((a - b)?),
 
         (a, b) => mismatch!("cannot subtract {1} from {0}", a, b),
@@ -293,6 +293,61 @@ pub fn div(lhs: Value, rhs: Value) -> StrResult<Value> {
     })
 }
 
+/// Compute the remainder of two values.
+///
+/// The result always has the same sign as the dividend.
+pub fn rem(lhs: Value, rhs: Value) -> StrResult<Value> {
+    if is_zero(&rhs) {
+        bail!("cannot divide by zero");
+    }
+
+    Ok(match (lhs, rhs) {
+        (Int(a), Int(b)) => Int(a.wrapping_rem(b)),
+        (Int(a), Float(b)) => Float(a as f64 % b),
+        (Float(a), Int(b)) => Float(a % b as f64),
+        (Float(a), Float(b)) => Float(a % b),
+
+        (a, b) => mismatch!("cannot compute remainder of {} and {}", a, b),
+    })
+}
+
+/// Raise a value to the power of another value.
+pub fn pow(lhs: Value, rhs: Value) -> StrResult<Value> {
+    if is_zero(&lhs) && is_zero(&rhs) {
+        bail!("zero to the power of zero is undefined");
+    }
+
+    let result = match (lhs, rhs) {
+        (Int(a), Int(b)) if b >= 0 => Int(match a {
+            // These stay small for arbitrarily large exponents.
+            0 | 1 => a,
+            -1 => {
+                if b % 2 == 0 {
+                    1
+                } else {
+                    -1
+                }
+            }
+            _ => {
+                let b = u32::try_from(b).map_err(|_| too_large())?;
+                a.checked_pow(b).ok_or_else(too_large)?
+            }
+        }),
+        (Int(a), Int(b)) => Float((a as f64).powf(b as f64)),
+        (Int(a), Float(b)) => Float((a as f64).powf(b)),
+        (Float(a), Int(b)) => Float(a.powf(b as f64)),
+        (Float(a), Float(b)) => Float(a.powf(b)),
+
+        (a, b) => mismatch!("cannot raise {} to the power of {}", a, b),
+    };
+
+    if matches!(result, Float(v) if v.is_nan()) {
+        bail!("the result is not a real number");
+    }
+
+    Ok(result)
+}
+
 /// Whether a value is a numeric zero.
 fn is_zero(v: &Value) -> bool {
     match *v {
@@ -343,6 +398,22 @@ pub fn or(lhs: Value, rhs: Value) -> StrResult<Value> {
     }
 }
 
+/// Create an array of the integers from `lhs` up to, but excluding, `rhs`.
+pub fn range(lhs: Value, rhs: Value) -> StrResult<Value> {
+    match (lhs, rhs) {
+        (Int(a), Int(b)) => Ok(Array((a..b).map(Int).collect())),
+        (a, b) => mismatch!("cannot create a range from {} to {}", a, b),
+    }
+}
+
+/// Create an array of the integers from `lhs` up to and including `rhs`.
+pub fn range_inclusive(lhs: Value, rhs: Value) -> StrResult<Value> {
+    match (lhs, rhs) {
+        (Int(a), Int(b)) => Ok(Array((a..=b).map(Int).collect())),
+        (a, b) => mismatch!("cannot create a range from {} to {}", a, b),
+    }
+}
+
 /// Compute whether two values are equal.
 pub fn eq(lhs: Value, rhs: Value) -> StrResult<Value> {
     Ok(Bool(equal(&lhs, &rhs)))
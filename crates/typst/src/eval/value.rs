@@ -16,7 +16,7 @@ use super::{
     IntoValue, Module, NativeType, NoneValue, Plugin, Reflect, Scope, Str, Symbol, Type,
     Version,
 };
-use crate::diag::StrResult;
+use crate::diag::{HintedStrResult, StrResult};
 use crate::eval::Datetime;
 use crate::geom::{Abs, Angle, Color, Em, Fr, Gradient, Length, Ratio, Rel};
 use crate::model::{Label, Styles};
@@ -153,17 +153,17 @@ impl Value {
     }
 
     /// Try to access a field on the value.
-    pub fn field(&self, field: &str) -> StrResult<Value> {
-        match self {
-            Self::Symbol(symbol) => symbol.clone().modified(field).map(Self::Symbol),
-            Self::Version(version) => version.component(field).map(Self::Int),
-            Self::Dict(dict) => dict.get(field).cloned(),
-            Self::Content(content) => content.field_by_name(field),
-            Self::Type(ty) => ty.field(field).cloned(),
-            Self::Func(func) => func.field(field).cloned(),
-            Self::Module(module) => module.field(field).cloned(),
-            _ => fields::field(self, field),
-        }
+    pub fn field(&self, field: &str) -> HintedStrResult<Value> {
+        Ok(match self {
+            Self::Symbol(symbol) => symbol.clone().modified(field).map(Self::Symbol)?,
+            Self::Version(version) => version.component(field).map(Self::Int)?,
+            Self::Dict(dict) => dict.get(field)?.clone(),
+            Self::Content(content) => content.field_by_name(field)?,
+            Self::Type(ty) => ty.field(field).cloned()?,
+            Self::Func(func) => func.field(field).cloned()?,
+            Self::Module(module) => module.field(field).cloned()?,
+            _ => fields::field(self, field)?,
+        })
     }
 
     /// The associated scope, if this is a function, type, or module.
@@ -434,7 +434,11 @@ impl Color {
         /// Alternatively: The color in hexadecimal notation.
         ///
         /// Accepts three, four, six or eight hexadecimal digits and optionally
-        /// a leading hash.
+        /// a leading hash. CSS color names like `{"cornflowerblue"}` and the
+        /// CSS notations `{"rgb(100, 149, 237)"}` and `{"rgba(0, 0, 0, 0.5)"}`
+        /// are accepted, too. Note that the names follow the CSS definitions
+        /// and thus differ from Typst's predefined colors: `{rgb("red")}` is
+        /// `#ff0000` while `{red}` is `#ff4136`.
         ///
         /// If this is given, the individual components should not be given.
         ///
@@ -1034,6 +1038,17 @@ impl Color {
         ))
     }
 
+    /// Looks up a CSS color name like `cornflowerblue`, ignoring case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        // The table is lowercase, so lowercasing the name on the fly during
+        // the comparison avoids an allocation.
+        let name = name.bytes().map(|b| b.to_ascii_lowercase());
+        NAMED_COLORS
+            .binary_search_by(|&(key, _)| key.bytes().cmp(name.clone()))
+            .ok()
+            .map(|i| Self::from_u32(NAMED_COLORS[i].1))
+    }
+
     /// Converts a 32-bit integer to an RGBA color.
     #[inline]
     pub fn from_u32(color: u32) -> Self {
@@ -1339,7 +1354,20 @@ impl FromStr for Color {
     /// - `abcdefff` (with alpha).
     ///
     /// The hash is optional and both lower and upper case are fine.
+    ///
+    /// Also accepts the CSS color names from [`NAMED_COLORS`], ignoring case,
+    /// and the CSS functional notations `rgb(r, g, b)` and `rgba(r, g, b, a)`.
     fn from_str(hex_str: &str) -> Result<Self, Self::Err> {
+        if let Some(color) = Self::from_name(hex_str) {
+            return Ok(color);
+        }
+
+        if let Some(args) =
+            hex_str.strip_prefix("rgba(").or_else(|| hex_str.strip_prefix("rgb("))
+        {
+            return parse_css_rgb(args);
+        }
+
         let hex_str = hex_str.strip_prefix('#').unwrap_or(hex_str);
         if hex_str.chars().any(|c| !c.is_ascii_hexdigit()) {
             return Err("color string contains non-hexadecimal letters");
@@ -1371,6 +1399,197 @@ impl FromStr for Color {
     }
 }
 
+/// Parses the arguments of a CSS `rgb(..)` or `rgba(..)` color, that is,
+/// everything after the opening parenthesis.
+///
+/// The red, green, and blue components are numbers from 0 to 255 and the
+/// optional alpha component is a number from 0 to 1. Each of them can also be
+/// given as a percentage.
+fn parse_css_rgb(args: &str) -> Result<Color, &'static str> {
+    let Some(args) = args.strip_suffix(')') else {
+        return Err("color string is missing a closing parenthesis");
+    };
+
+    let parts: Vec<&str> = args.split(',').map(str::trim).collect();
+    if parts.len() != 3 && parts.len() != 4 {
+        return Err("color string has wrong number of components");
+    }
+
+    let mut values: [u8; 4] = [u8::MAX; 4];
+    for (i, part) in parts.into_iter().enumerate() {
+        let (number, max) = match part.strip_suffix('%') {
+            Some(percent) => (percent, 100.0),
+            None if i == 3 => (part, 1.0),
+            None => (part, 255.0),
+        };
+
+        let Ok(value) = number.parse::<f64>() else {
+            return Err("color component is not a number");
+        };
+
+        if !(0.0..=max).contains(&value) {
+            return Err("color component is out of range");
+        }
+
+        values[i] = (value / max * 255.0).round() as u8;
+    }
+
+    Ok(Color::from_u8(values[0], values[1], values[2], values[3]))
+}
+
+/// The named colors from CSS Color Module Level 4, sorted by name, as
+/// `0xRRGGBBAA` values.
+pub const NAMED_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xf0f8ffff),
+    ("antiquewhite", 0xfaebd7ff),
+    ("aqua", 0x00ffffff),
+    ("aquamarine", 0x7fffd4ff),
+    ("azure", 0xf0ffffff),
+    ("beige", 0xf5f5dcff),
+    ("bisque", 0xffe4c4ff),
+    ("black", 0x000000ff),
+    ("blanchedalmond", 0xffebcdff),
+    ("blue", 0x0000ffff),
+    ("blueviolet", 0x8a2be2ff),
+    ("brown", 0xa52a2aff),
+    ("burlywood", 0xdeb887ff),
+    ("cadetblue", 0x5f9ea0ff),
+    ("chartreuse", 0x7fff00ff),
+    ("chocolate", 0xd2691eff),
+    ("coral", 0xff7f50ff),
+    ("cornflowerblue", 0x6495edff),
+    ("cornsilk", 0xfff8dcff),
+    ("crimson", 0xdc143cff),
+    ("cyan", 0x00ffffff),
+    ("darkblue", 0x00008bff),
+    ("darkcyan", 0x008b8bff),
+    ("darkgoldenrod", 0xb8860bff),
+    ("darkgray", 0xa9a9a9ff),
+    ("darkgreen", 0x006400ff),
+    ("darkgrey", 0xa9a9a9ff),
+    ("darkkhaki", 0xbdb76bff),
+    ("darkmagenta", 0x8b008bff),
+    ("darkolivegreen", 0x556b2fff),
+    ("darkorange", 0xff8c00ff),
+    ("darkorchid", 0x9932ccff),
+    ("darkred", 0x8b0000ff),
+    ("darksalmon", 0xe9967aff),
+    ("darkseagreen", 0x8fbc8fff),
+    ("darkslateblue", 0x483d8bff),
+    ("darkslategray", 0x2f4f4fff),
+    ("darkslategrey", 0x2f4f4fff),
+    ("darkturquoise", 0x00ced1ff),
+    ("darkviolet", 0x9400d3ff),
+    ("deeppink", 0xff1493ff),
+    ("deepskyblue", 0x00bfffff),
+    ("dimgray", 0x696969ff),
+    ("dimgrey", 0x696969ff),
+    ("dodgerblue", 0x1e90ffff),
+    ("firebrick", 0xb22222ff),
+    ("floralwhite", 0xfffaf0ff),
+    ("forestgreen", 0x228b22ff),
+    ("fuchsia", 0xff00ffff),
+    ("gainsboro", 0xdcdcdcff),
+    ("ghostwhite", 0xf8f8ffff),
+    ("gold", 0xffd700ff),
+    ("goldenrod", 0xdaa520ff),
+    ("gray", 0x808080ff),
+    ("green", 0x008000ff),
+    ("greenyellow", 0xadff2fff),
+    ("grey", 0x808080ff),
+    ("honeydew", 0xf0fff0ff),
+    ("hotpink", 0xff69b4ff),
+    ("indianred", 0xcd5c5cff),
+    ("indigo", 0x4b0082ff),
+    ("ivory", 0xfffff0ff),
+    ("khaki", 0xf0e68cff),
+    ("lavender", 0xe6e6faff),
+    ("lavenderblush", 0xfff0f5ff),
+    ("lawngreen", 0x7cfc00ff),
+    ("lemonchiffon", 0xfffacdff),
+    ("lightblue", 0xadd8e6ff),
+    ("lightcoral", 0xf08080ff),
+    ("lightcyan", 0xe0ffffff),
+    ("lightgoldenrodyellow", 0xfafad2ff),
+    ("lightgray", 0xd3d3d3ff),
+    ("lightgreen", 0x90ee90ff),
+    ("lightgrey", 0xd3d3d3ff),
+    ("lightpink", 0xffb6c1ff),
+    ("lightsalmon", 0xffa07aff),
+    ("lightseagreen", 0x20b2aaff),
+    ("lightskyblue", 0x87cefaff),
+    ("lightslategray", 0x778899ff),
+    ("lightslategrey", 0x778899ff),
+    ("lightsteelblue", 0xb0c4deff),
+    ("lightyellow", 0xffffe0ff),
+    ("lime", 0x00ff00ff),
+    ("limegreen", 0x32cd32ff),
+    ("linen", 0xfaf0e6ff),
+    ("magenta", 0xff00ffff),
+    ("maroon", 0x800000ff),
+    ("mediumaquamarine", 0x66cdaaff),
+    ("mediumblue", 0x0000cdff),
+    ("mediumorchid", 0xba55d3ff),
+    ("mediumpurple", 0x9370dbff),
+    ("mediumseagreen", 0x3cb371ff),
+    ("mediumslateblue", 0x7b68eeff),
+    ("mediumspringgreen", 0x00fa9aff),
+    ("mediumturquoise", 0x48d1ccff),
+    ("mediumvioletred", 0xc71585ff),
+    ("midnightblue", 0x191970ff),
+    ("mintcream", 0xf5fffaff),
+    ("mistyrose", 0xffe4e1ff),
+    ("moccasin", 0xffe4b5ff),
+    ("navajowhite", 0xffdeadff),
+    ("navy", 0x000080ff),
+    ("oldlace", 0xfdf5e6ff),
+    ("olive", 0x808000ff),
+    ("olivedrab", 0x6b8e23ff),
+    ("orange", 0xffa500ff),
+    ("orangered", 0xff4500ff),
+    ("orchid", 0xda70d6ff),
+    ("palegoldenrod", 0xeee8aaff),
+    ("palegreen", 0x98fb98ff),
+    ("paleturquoise", 0xafeeeeff),
+    ("palevioletred", 0xdb7093ff),
+    ("papayawhip", 0xffefd5ff),
+    ("peachpuff", 0xffdab9ff),
+    ("peru", 0xcd853fff),
+    ("pink", 0xffc0cbff),
+    ("plum", 0xdda0ddff),
+    ("powderblue", 0xb0e0e6ff),
+    ("purple", 0x800080ff),
+    ("rebeccapurple", 0x663399ff),
+    ("red", 0xff0000ff),
+    ("rosybrown", 0xbc8f8fff),
+    ("royalblue", 0x4169e1ff),
+    ("saddlebrown", 0x8b4513ff),
+    ("salmon", 0xfa8072ff),
+    ("sandybrown", 0xf4a460ff),
+    ("seagreen", 0x2e8b57ff),
+    ("seashell", 0xfff5eeff),
+    ("sienna", 0xa0522dff),
+    ("silver", 0xc0c0c0ff),
+    ("skyblue", 0x87ceebff),
+    ("slateblue", 0x6a5acdff),
+    ("slategray", 0x708090ff),
+    ("slategrey", 0x708090ff),
+    ("snow", 0xfffafaff),
+    ("springgreen", 0x00ff7fff),
+    ("steelblue", 0x4682b4ff),
+    ("tan", 0xd2b48cff),
+    ("teal", 0x008080ff),
+    ("thistle", 0xd8bfd8ff),
+    ("tomato", 0xff6347ff),
+    ("turquoise", 0x40e0d0ff),
+    ("violet", 0xee82eeff),
+    ("wheat", 0xf5deb3ff),
+    ("white", 0xffffffff),
+    ("whitesmoke", 0xf5f5f5ff),
+    ("yellow", 0xffff00ff),
+    ("yellowgreen", 0x9acd32ff),
+];
+
 /// An 8-bit CMYK color.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Cmyk {
@@ -1644,6 +1863,20 @@ mod tests {
         test("fCd2a9AD", 0xfc, 0xd2, 0xa9, 0xad);
         test("233", 0x22, 0x33, 0x33, 255);
         test("111b", 0x11, 0x11, 0x11, 0xbb);
+        test("cornflowerblue", 0x64, 0x95, 0xed, 255);
+        test("RebeccaPurple", 0x66, 0x33, 0x99, 255);
+        test("red", 0xff, 0x00, 0x00, 255);
+        test("rgb(100, 149, 237)", 0x64, 0x95, 0xed, 255);
+        test("rgb(100%,0%,50%)", 0xff, 0x00, 0x80, 255);
+        test("rgba(255, 0, 0, 0.5)", 0xff, 0x00, 0x00, 0x80);
+        test("rgba(0, 0, 0, 25%)", 0x00, 0x00, 0x00, 0x40);
+        test("rgb(1, 2, 3, 1)", 0x01, 0x02, 0x03, 255);
+    }
+
+    #[test]
+    fn test_named_colors_are_sorted() {
+        assert_eq!(NAMED_COLORS.len(), 148);
+        assert!(NAMED_COLORS.windows(2).all(|w| w[0].0 < w[1].0));
     }
 
     #[test]
@@ -1658,5 +1891,12 @@ mod tests {
         test("f075ff011", "color string has wrong length");
         test("hmmm", "color string contains non-hexadecimal letters");
         test("14B2AH", "color string contains non-hexadecimal letters");
+        test("rgb(1, 2, 3", "color string is missing a closing parenthesis");
+        test("rgb(1, 2)", "color string has wrong number of components");
+        test("rgba(1, 2, 3, 4, 5)", "color string has wrong number of components");
+        test("rgb(1, two, 3)", "color component is not a number");
+        test("rgb(256, 0, 0)", "color component is out of range");
+        test("rgba(0, 0, 0, 2)", "color component is out of range");
+        test("rgb(0, 0, 120%)", "color component is out of range");
     }
 }
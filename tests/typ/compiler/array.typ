@@ -184,6 +184,18 @@
 // Error: 20-22 unexpected argument
 #(1, 2, 3).fold(0, () => none)
 
+---
+// Test the `reduce` method.
+// The reducer is never called on an empty array.
+#test(().reduce((..) => panic()), none)
+#test((5,).reduce((s, x) => s + x), 5)
+#test((1, 2, 3, 4).reduce((s, x) => s + x), 10)
+#test(("a", "b", "c").reduce((s, x) => x + s), "cba")
+
+---
+// Error: 19-21 unexpected argument
+#(1, 2, 3).reduce(() => none)
+
 ---
 // Test the `sum` method.
 #test(().sum(default: 0), 0)
@@ -267,6 +279,14 @@
 #test((1, 2, 3).zip(), ((1,), (2,), (3,)))
 #test(array.zip(()), ())
 
+#{
+  let out = ()
+  for (name, value) in ("a", "b", "c").zip((1, 2)) {
+    out.push(name + str(value))
+  }
+  test(out, ("a1", "b2"))
+}
+
 
 ---
 // Test the `enumerate` method.
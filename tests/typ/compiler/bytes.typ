@@ -11,6 +11,15 @@
 ---
 #test(str(bytes(range(0x41, 0x50))), "ABCDEFGHIJKLMNO")
 #test(array(bytes("Hello")), (0x48, 0x65, 0x6C, 0x6C, 0x6F))
+#test(array(bytes((0, 127, 255))), (0, 127, 255))
+#test(bytes(array(bytes("Typst"))), bytes("Typst"))
+
+---
+// Test the length of bytes.
+#test(bytes(()).len(), 0)
+#test(bytes("").len(), 0)
+#test(bytes("ä").len(), 2)
+#test(bytes("Hello 😃").len(), 10)
 
 ---
 // Test addition and joining.
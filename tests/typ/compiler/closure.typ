@@ -46,6 +46,16 @@
   test(greet("Typst"), "Hi, Typst!")
 }
 
+---
+// Capture loop variables.
+#{
+  let fs = ()
+  for i in range(3) {
+    fs.push(x => x + i)
+  }
+  test(fs.map(f => f(10)), (10, 11, 12))
+}
+
 ---
 // Redefined variable.
 #{
@@ -32,6 +32,7 @@
 #{
   let dict = (a: 1, b: 2)
   // Error: 11-23 dictionary does not contain key "c" and no default value was specified
+  // Hint: 11-23 available keys are "a", "b"
   let x = dict.at("c")
 }
 
@@ -60,6 +61,15 @@
   dict.b += 1
 }
 
+---
+// Missing lvalue in a non-empty dictionary.
+#{
+  let dict = (a: 1)
+  // Error: 3-9 dictionary does not contain key "b" and no default value was specified
+  // Hint: 3-9 available keys are "a"
+  dict.b += 1
+}
+
 ---
 // Test dictionary methods.
 #let dict = (a: 3, c: 2, b: 1)
@@ -72,6 +82,24 @@
 #test("c" in dict, false)
 #test(dict, (a: 3, b: 1))
 
+---
+// Test dictionary introspection.
+#test((:).keys(), ())
+#test((:).values(), ())
+#test((:).pairs(), ())
+
+#let dict = (z: none, a: "a", m: (1, 2))
+#dict.insert("b", 2pt)
+#test(dict.keys(), ("z", "a", "m", "b"))
+#test(dict.values(), (none, "a", (1, 2), 2pt))
+#test(dict.pairs(), (("z", none), ("a", "a"), ("m", (1, 2)), ("b", 2pt)))
+
+#let out = ()
+#for (key, value) in dict {
+  out.push(key + ": " + repr(value))
+}
+#test(out, ("z: none", "a: \"a\"", "m: (1, 2)", "b: 2pt"))
+
 ---
 // Test that removal keeps order.
 #let dict = (a: 1, b: 2, c: 3, d: 4)
@@ -74,6 +74,10 @@
 #test(datetime(hour: 13, minute: 0, second: 0) - a, duration(seconds: 3600))
 #test(datetime(day: 1, month: 2, year: 2000) - b, duration(days: 31))
 #test(datetime(day: 15, month: 1, year: 2000) - b, duration(weeks: 2))
+#test(datetime(day: 1, month: 3, year: 2000) - b, duration(days: 60))
+#test(datetime(day: 1, month: 1, year: 2001) - b, duration(days: 366))
+#test(b - datetime(day: 1, month: 1, year: 2001), duration(days: -366))
+#test((datetime(day: 1, month: 1, year: 2002) - b).days(), 731)
 
 ---
 // Test multiplying and dividing durations with numbers.
@@ -102,3 +106,17 @@
 #test(duration(minutes: 20) < duration(minutes: 10), false)
 #test(duration(minutes: 20) <= duration(minutes: 10), false)
 #test(duration(minutes: 20) == duration(minutes: 10), false)
+
+---
+// Test adding a duration to the same datetime in either order.
+#let d = datetime(day: 1, month: 1, year: 2000)
+#test(duration(days: 3) + d, d + duration(days: 3))
+#test(d + duration(days: 3) - duration(days: 3), d)
+
+---
+// Error: 3-63 datetime is out of range
+#(datetime(year: 9999, month: 12, day: 31) + duration(days: 1))
+
+---
+// Error: 3-62 datetime is out of range
+#(datetime(year: -9999, month: 1, day: 1) - duration(days: 1))
@@ -46,10 +46,25 @@
 // Error: 4-11 cannot access fields on user-defined functions
 #f.invalid
 
+---
+// Test chained field access.
+#let config = (page: (margin: (top: 1cm)))
+#test(config.page.margin.top, 1cm)
+
 ---
 // Error: 6-13 dictionary does not contain key "invalid"
 #(:).invalid
 
+---
+// Error: 19-22 dictionary does not contain key "bar"
+// Hint: 19-22 available keys are "foo", "baz"
+#(foo: 1, baz: 2).bar
+
+---
+// Error: 78-79 dictionary does not contain key "z"
+// Hint: 78-79 available keys are "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", …
+#(a: 1, b: 2, c: 3, d: 4, e: 5, f: 6, g: 7, h: 8, i: 9, j: 10, k: 11, l: 12).z
+
 ---
 // Error: 8-10 cannot access fields on type boolean
 #false.ok
@@ -78,6 +78,17 @@
 #test(for v in "" [], none)
 #test(type(for v in "1" []), content)
 
+---
+// Values of integer ranges.
+#let out = ()
+#for v in 1..3 {
+  out += (v,)
+}
+#for v in 1..=3 {
+  out += (v,)
+}
+#test(out, (1, 2, 1, 2, 3))
+
 ---
 // Uniterable expression.
 // Error: 11-15 cannot loop over boolean
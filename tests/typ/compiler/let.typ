@@ -135,6 +135,10 @@ Three
 // Error: 6-20 not enough elements to destructure
 #let (..a, b, c, d) = (1, 2)
 
+---
+// Error: 6-12 too many elements to destructure
+#let (a, b) = (1, 2, 3)
+
 ---
 // Error: 6-12 cannot destructure boolean
 #let (a, b) = true
@@ -190,10 +194,12 @@ Three
 
 ---
 // Error: 10-11 dictionary does not contain key "b"
+// Hint: 10-11 available keys are "a"
 #let (a, b) = (a: 1)
 
 ---
 // Error: 10-11 dictionary does not contain key "b"
+// Hint: 10-11 available keys are "a"
 #let (a, b: b) = (a: 1)
 
 ---
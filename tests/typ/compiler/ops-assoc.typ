@@ -7,6 +7,11 @@
 #test(10 / 2 / 2 == 10 / (2 / 2), false)
 #test(1 / 2 * 3, 1.5)
 
+---
+// Exponentiation is right-associative.
+#test(2 ^ 3 ^ 2, 512)
+#test((2 ^ 3) ^ 2, 64)
+
 ---
 // Assignment is right-associative.
 {
@@ -25,6 +25,22 @@
 // Error: 3-9 cannot apply 'not' to array
 #(not ())
 
+---
+// Error: 3-10 cannot compare integer and string
+#(1 < "2")
+
+---
+// Error: 3-13 cannot apply 'and' to boolean and integer
+#(true and 1)
+
+---
+// Error: 3-14 cannot apply 'or' to boolean and string
+#(false or "")
+
+---
+// Error: 3-9 cannot create a range from integer to float
+#(1..2.5)
+
 ---
 // Error: 3-19 cannot compare relative length and ratio
 #(30% + 1pt <= 40%)
@@ -49,6 +65,34 @@
 // Error: 3-15 cannot divide by zero
 #(15deg / 0deg)
 
+---
+// Error: 3-9 cannot divide by zero
+#(10 % 0)
+
+---
+// Error: 3-12 cannot divide by zero
+#(2.5 % 0.0)
+
+---
+// Error: 3-11 cannot compute remainder of length and integer
+#(10pt % 3)
+
+---
+// Error: 3-9 value is too large
+#(2 ^ 63)
+
+---
+// Error: 3-8 zero to the power of zero is undefined
+#(0 ^ 0)
+
+---
+// Error: 3-15 the result is not a real number
+#((-8.0) ^ 0.5)
+
+---
+// Error: 3-11 cannot raise string to the power of integer
+#("ab" ^ 2)
+
 ---
 // Special messages for +, -, * and /.
 // Error: 3-10 cannot add integer and string
@@ -124,3 +168,13 @@
 // (since then it doesn't resolve to the standard library version anymore).
 #let rect = ""
 #(rect = "hi")
+
+---
+// A percent sign after a number is the remainder operator if an operand
+// follows directly and a ratio suffix otherwise.
+#test(10%3, 1)
+#test(10%(3), 1)
+#let x = 4
+#test(10%x, 2)
+// Error: 3-8 cannot subtract integer from ratio
+#(10%-3)
@@ -11,6 +11,11 @@
 // Boolean operations bind stronger than '=='.
 #test("a" == "a" and 2 < 3, true)
 #test(not "b" == "b", false)
+#test(not 1 < 2, false)
+
+// And binds stronger than or.
+#test(true or false and false, true)
+#test((true or false) and false, false)
 
 ---
 // Assignment binds stronger than boolean operations.
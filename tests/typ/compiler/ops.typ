@@ -50,6 +50,28 @@
 #test(12pt/.4, 30pt)
 #test(7 / 2, 3.5)
 
+// Remainder.
+#test(10 % 3, 1)
+#test(-10 % 3, -1)
+#test(10 % -3, 1)
+#test(10.5 % 3.0, 1.5)
+#test(7 % 2.5, 2.0)
+#test(2 * 7 % 4, 2)
+#test((-9223372036854775807 - 1) % -1, 0)
+
+// Exponentiation.
+#test(2 ^ 10, 1024)
+#test(5 ^ 0, 1)
+#test(2 ^ -1, 0.5)
+#test(-2 ^ 2, -4)
+#test((-2) ^ 2, 4)
+#test(4.0 ^ 0.5, 2.0)
+#test(3 * 2 ^ 2, 12)
+#test(0 ^ 5000000000, 0)
+#test(1 ^ 5000000000, 1)
+#test((-1) ^ 5000000000, 1)
+#test((-1) ^ 5000000001, -1)
+
 // Combination.
 #test(3-4 * 5 < -10, true)
 #test({ let x; x = 1 + 4*5 >= 21 and { x = "a"; x + "b" == "ab" }; x }, true)
@@ -206,6 +228,29 @@
 #test(50% < 40% + 0pt, false)
 #test(40% + 0pt < 50% + 0pt, true)
 #test(1em < 2em, true)
+#test(2pt >= 2pt, true)
+#test(1cm > 1mm, true)
+#test(1 < 1.5, true)
+#test(2.5 >= 2, true)
+#test(3 != 3.0, false)
+#test("a" < "b", true)
+#test("abc" <= "ab", false)
+#test("Z" < "a", true)
+
+---
+// Test range operators.
+#test(0..3, (0, 1, 2))
+#test(0..=3, (0, 1, 2, 3))
+#test(-2..1, (-2, -1, 0))
+#test(3..3, ())
+#test(3..=3, (3,))
+#test(5..2, ())
+#test(0..1 + 2, range(3))
+#test(1..4 == (1, 2, 3), true)
+#test((0..5).map(i => i * i), (0, 1, 4, 9, 16))
+#let n = 4
+#test(for i in 0..n { (i,) }, (0, 1, 2, 3))
+#test((1, ..0..2, ..2..=3), (1, 0, 1, 2, 3))
 
 ---
 // Test assignment operators.
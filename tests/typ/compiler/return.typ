@@ -29,6 +29,22 @@
 #test(f(1), "ac")
 #test(f(2), "ad")
 
+---
+// Test early return from nested loops and conditionals.
+#let find(haystack, needle) = {
+  for (i, row) in haystack.enumerate() {
+    for (j, item) in row.enumerate() {
+      if item == needle {
+        return (i, j)
+      }
+    }
+  }
+  none
+}
+
+#test(find(((1, 2), (3, 4)), 3), (1, 0))
+#test(find(((1, 2), (3, 4)), 5), none)
+
 ---
 // Test return with joining and content.
 // Ref: true
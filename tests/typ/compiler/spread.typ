@@ -40,6 +40,8 @@
   test(calc.min(1, 2, ..more), -3)
   test(calc.max(..more, 9), 10)
   test(calc.max(..more, 11), 11)
+  test(calc.max(..(), 1, ..()), 1)
+  test(calc.min(..more, ..(-5, 0)), -5)
 }
 
 #{
@@ -48,6 +50,17 @@
   test(tostr(a: 1, ..more, b: 2), "(a: 1, c: 3, d: 4, b: 2)")
 }
 
+---
+// Test forwarding named arguments from dictionaries.
+#{
+  let f(a: 0, b: 0) = (a, b)
+  test(f(..(:)), (0, 0))
+  test(f(a: 1, ..(a: 2, b: 3)), (2, 3))
+  test(f(..(a: 2), a: 1), (1, 0))
+  test(calc.round(1.234, ..(:)), 1.0)
+  test(calc.round(1.234, ..(digits: 2)), 1.23)
+}
+
 ---
 // None is spreadable.
 #let f() = none
@@ -4,6 +4,10 @@
 ---
 // Test the `len` method.
 #test("Hello World!".len(), 12)
+#test("".len(), 0)
+#test("ä".len(), 2)
+#test("😀".len(), 4)
+#test("😀".codepoints().len(), 1)
 
 ---
 // Test the `first` and `last` methods.
@@ -92,6 +96,8 @@
 #test("Hello World".position("World"), 6)
 #test("It's 12:13 now".find(date), "12:13")
 #test("It's 12:13 now".position(date), 5)
+#test("Hello World".find(regex("(?i)WORLD")), "World")
+#test("Hello World".find(regex("WORLD")), none)
 
 ---
 // Test the `match` method.
@@ -101,6 +107,11 @@
   (start: 4, end: 8, text: "time", captures: ()),
 )
 
+#test(
+  "Date: 2023-11-05".match(regex("(\d+)-(\d+)-(\d+)")).captures,
+  ("2023", "11", "05"),
+)
+
 // Test the `matches` method.
 #test("Hello there".matches("\d"), ())
 #test("Day by Day.".matches("Day"), (
@@ -201,6 +212,7 @@
 #test("123abc456".trim(regex("\d+"), at: end, repeat: false), "123abc")
 #test("123abc456".trim(regex("\d{1,2}$"), repeat: false), "123abc4")
 #test("hello world".trim(regex(".")), "")
+#test("\u{3000}abc\u{a0}\u{2003}".trim(), "abc")
 
 ---
 // Error: 17-21 expected either `start` or `end`
@@ -212,6 +224,7 @@
 #test("abc".split("b"), ("a", "c"))
 #test("a123c".split(regex("\d")), ("a", "", "", "c"))
 #test("a123c".split(regex("\d+")), ("a", "c"))
+#test("a🏳️‍🌈b🏳️‍🌈c".split("🏳️‍🌈"), ("a", "b", "c"))
 
 ---
 // Test the `rev` method.
@@ -222,6 +235,25 @@
 // Error: 12-15 unknown variable: arg
 #"abc".rev(arg)
 
+---
+// Test Unicode escape sequences.
+#test("\u{1F600}", "😀")
+#test("\u{10FFFF}".len(), 4)
+#test("\u{41}\u{308}".clusters().len(), 1)
+#test("\\u{41}".len(), 7)
+
+---
+// Error: 2-12 invalid Unicode codepoint: d800
+#"\u{d800}"
+
+---
+// Error: 2-14 invalid Unicode codepoint: 110000
+#"\u{110000}"
+
+---
+// Error: 2-9 unclosed Unicode escape sequence
+#"\u{41"
+
 ---
 // Error: 2-2:1 unclosed string
 #"hello\"
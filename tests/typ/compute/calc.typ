@@ -18,10 +18,27 @@
 #test(float("-7654.321"), -7654.321)
 #test(float("\u{2212}7654.321"), -7654.321)
 #test(type(float(10)), float)
+#test(float("1e10"), 1e10)
+#test(type(float("nan")), float)
+#test(int(str(-42)), -42)
+#test(float(str(3.14)), 3.14)
 
 ---
 #test(calc.round(calc.e, digits: 2), 2.72)
 #test(calc.round(calc.pi, digits: 2), 3.14)
+#test(calc.tau, 2 * calc.pi)
+#test(calc.round(calc.sin(calc.pi / 2), digits: 10), 1.0)
+
+---
+// Constants can be shadowed without affecting the module.
+#{
+  let pi = 3
+  test(pi, 3)
+  test(calc.round(calc.pi, digits: 2), 3.14)
+}
+
+#let f() = calc.pi
+#test(f(), calc.pi)
 
 ---
 // Error: 6-10 expected boolean, float, string, or integer, found length
@@ -39,6 +56,14 @@
 // Error: 8-15 invalid float: 1.2.3
 #float("1.2.3")
 
+---
+// Error: 6-27 invalid integer: 9223372036854775808
+#int("9223372036854775808")
+
+---
+// Error: 6-12 invalid integer: 0xff
+#int("0xff")
+
 ---
 // Test the `abs` function.
 #test(calc.abs(-3), 3)
@@ -92,12 +117,56 @@
 // Error: 16-19 divisor must not be zero
 #calc.quo(3.0, 0.0)
 
+---
+// Test the trigonometric functions.
+#test(calc.sin(90deg), 1.0)
+#test(calc.sin(0), 0.0)
+#test(calc.cos(calc.pi), -1.0)
+#test(calc.round(calc.tan(45deg), digits: 10), 1.0)
+#test(calc.round(calc.asin(1).deg(), digits: 10), 90.0)
+#test(calc.round(calc.acos(0).deg(), digits: 10), 90.0)
+#test(calc.round(calc.atan(1).deg(), digits: 10), 45.0)
+#test(calc.round(calc.atan2(1, 1).deg(), digits: 10), 45.0)
+#test(calc.round(calc.atan2(0, 1).deg(), digits: 10), 90.0)
+
+---
+// Error: 12-13 value must be between -1 and 1
+#calc.asin(2)
+
+---
+// Test the rounding functions.
+#test(calc.floor(5), 5)
+#test(calc.floor(-3.5), -4)
+#test(calc.ceil(-3.5), -3)
+#test(calc.trunc(-3.7), -3)
+#test(calc.fract(-3.25), -0.25)
+#test(calc.round(7), 7)
+#test(calc.round(2.5), 3.0)
+#test(calc.round(-3.5), -4.0)
+#test(calc.round(3.14159, digits: 3), 3.142)
+#test(calc.round(3.14159, digits: 0), 3.0)
+
 ---
 // Test the `min` and `max` functions.
 #test(calc.min(2, -4), -4)
 #test(calc.min(3.5, 1e2, -0.1, 3), -0.1)
 #test(calc.max(-3, 11), 11)
 #test(calc.min("hi"), "hi")
+#test(calc.min(1pt, -2pt, 3cm), -2pt)
+#test(calc.max(1pt, -2pt, 3mm), 3mm)
+
+---
+// Test the `clamp` and `abs` functions.
+#test(calc.clamp(5, 0, 3), 3)
+#test(calc.clamp(-5, 0, 3), 0)
+#test(calc.clamp(1.5, 0, 3), 1.5)
+#test(calc.clamp(-1.5, -1, 1.0), -1.0)
+#test(calc.abs(-3pt), 3pt)
+#test(calc.abs(-5deg), 5deg)
+
+---
+// Error: 19-20 max must be greater than or equal to min
+#calc.clamp(1, 3, 2)
 
 ---
 // Test the `pow`, `log`, `exp`, and `ln` functions.
@@ -105,6 +174,10 @@
 #test(calc.pow(2, 4), 16)
 #test(calc.exp(2), calc.pow(calc.e, 2))
 #test(calc.ln(10), calc.log(10, base: calc.e))
+#test(calc.sqrt(4), 2.0)
+#test(calc.log(1), 0.0)
+#test(calc.round(calc.ln(calc.exp(1)), digits: 10), 1.0)
+#test(calc.pow(2, 10), 1024)
 
 ---
 // Error: 10-16 zero to the power of zero is undefined
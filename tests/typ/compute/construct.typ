@@ -13,6 +13,10 @@
 #test(rgb(40, 30, 20).darken(10%), rgb(36, 27, 18))
 #test(rgb("#133337").negate(), rgb(236, 204, 200))
 #test(white.lighten(100%), white)
+#test(white.lighten(50%), white)
+#test(black.darken(50%), black)
+#test(black.darken(100%), black)
+#test(luma(0).darken(50%), luma(0))
 
 // Color mixing, in Oklab space by default.
 #test(rgb(color.mix(rgb("#ff0000"), rgb("#00ff00"))), rgb("#d0a800"))
@@ -32,6 +36,30 @@
 #test(color.mix((rgb("#aaff00"), 50%), (rgb("#aa00ff"), 50%), space: rgb), rgb("#aa8080"))
 #test(color.mix((rgb("#aaff00"), 75%), (rgb("#aa00ff"), 25%), space: rgb), rgb("#aabf40"))
 
+---
+// Test HSL conversion to hex at the gamut boundaries.
+#test(color.hsl(0deg, 0%, 0%).to-hex(), "#000000")
+#test(color.hsl(0deg, 0%, 100%).to-hex(), "#ffffff")
+#test(color.hsl(0deg, 100%, 50%).to-hex(), "#ff0000")
+#test(color.hsl(120deg, 100%, 50%).to-hex(), "#00ff00")
+#test(color.hsl(240deg, 100%, 50%).to-hex(), "#0000ff")
+#test(color.hsl(360deg, 100%, 50%).to-hex(), "#ff0000")
+#test(color.hsl(0deg, 100%, 50%, 50%).to-hex(), "#ff000080")
+
+---
+// Test CSS color names.
+#test(rgb("cornflowerblue"), rgb("#6495ed"))
+#test(rgb("RebeccaPurple"), rgb("#663399"))
+#test(rgb("red"), rgb("#ff0000"))
+#test(rgb("lightgoldenrodyellow").to-hex(), "#fafad2")
+#test(repr(rgb("teal")), "rgb(\"#008080\")")
+
+---
+// Test CSS functional color notation.
+#test(rgb("rgb(100, 149, 237)"), rgb("#6495ed"))
+#test(rgb("rgb(100%, 0%, 50%)"), rgb(100%, 0%, 50%))
+#test(rgb("rgba(255, 0, 0, 0.5)"), rgb(255, 0, 0, 50%))
+
 ---
 // Test color conversion method kinds
 #test(rgb(rgb(10, 20, 30)).space(), rgb)
@@ -104,6 +132,10 @@
 // Error: 6-11 color string contains non-hexadecimal letters
 #rgb("lol")
 
+---
+// Error: 6-17 color string has wrong number of components
+#rgb("rgb(1, 2)")
+
 ---
 // Error: 5-7 missing argument: red component
 #rgb()
@@ -10,6 +10,15 @@
 #test(repr(ltr), "ltr")
 #test(repr((1, 2, false, )), "(1, 2, false)")
 
+---
+// Test that repr produces valid code for plain values.
+#for value in (
+  none, auto, true, 1, -2.5, 12pt, 50%, 90deg, 2fr,
+  "a\"b", (1, (2, 3)), (a: 1, b: "x"), rgb("#123456"),
+) {
+  test(eval(repr(value)), value)
+}
+
 ---
 // Test panic.
 // Error: 7-9 panicked
@@ -25,6 +34,17 @@
 // Error: 7-24 panicked with: "this is wrong"
 #panic("this is wrong")
 
+---
+// Test todo.
+// Warning: 6-8 not yet implemented
+#todo()
+
+---
+// Test that todo warns but doesn't stop compilation.
+// Warning: 6-33 not yet implemented: "write the conclusion", 1
+#todo("write the conclusion", 1)
+#test(1 + 1, 2)
+
 ---
 // Test failing assertions.
 // Error: 8-16 assertion failed
@@ -71,6 +91,14 @@
 #test(type(1), int)
 #test(type(ltr), direction)
 #test(type(10 / 3), float)
+#test(type(type), type)
+#test(type(x => x), function)
+#test(type(calc.min), function)
+#test(type("hi"), str)
+#test(type(1pt + 2%), relative)
+#test(type((:)), dictionary)
+#test(str(type(none)), "none")
+#test(str(type(1)), "integer")
 
 ---
 // Test the eval function.